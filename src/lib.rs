@@ -1,6 +1,12 @@
 use std::cmp::Ordering::*;
+use std::collections::VecDeque;
+use std::fs;
 use std::fs::File;
-use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::io;
+use std::io::{BufRead, BufReader, Cursor, Read, Seek, SeekFrom, Write};
+use std::os::unix::fs::MetadataExt;
+use std::thread;
+use std::time::Duration;
 
 use clap::Parser;
 
@@ -52,7 +58,7 @@ fn parse_bytes(s: &str) -> Result<TakeValue, String> {
 #[derive(Parser, Debug)]
 #[command(name = "tailr", version = "0.1.0", author = "Radish-Miyazaki <y.hidaka.kobe@gmail.com>", about = "Rust tail")]
 pub struct Cli {
-    #[arg(value_name = "FILE", help = "Input file(s)", required = true)]
+    #[arg(value_name = "FILE", help = "Input file(s)", default_value = "-")]
     files: Vec<String>,
     #[arg(value_name = "BYTES", short = 'c', long, help = "Number of bytes", value_parser = parse_bytes, conflicts_with = "lines")]
     bytes: Option<TakeValue>,
@@ -60,27 +66,89 @@ pub struct Cli {
     lines: TakeValue,
     #[arg(short, long, help = "Suppress headers")]
     quiet: bool,
+    #[arg(short, long, help = "Output appended data as the file grows")]
+    follow: bool,
+    #[arg(
+        short = 's',
+        long = "sleep-interval",
+        value_name = "SECONDS",
+        help = "Number of seconds to sleep between iterations",
+        default_value = "1.0"
+    )]
+    sleep_interval: f64,
+    #[arg(
+        short = 'F',
+        long,
+        help = "Same as --follow, but track files by name to handle rotation"
+    )]
+    retry: bool,
+    #[arg(
+        short = 'z',
+        long = "zero-terminated",
+        help = "Line delimiter is NUL, not newline"
+    )]
+    zero_terminated: bool,
+}
+
+struct FollowState {
+    filename: String,
+    offset: u64,
+    inode: Option<(u64, u64)>,
+    accessible: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FollowAction {
+    Reappeared,
+    Gone,
+    Truncated,
+    Unchanged,
+    Appended(u64),
+}
+
+fn classify(
+    old_offset: u64,
+    old_inode: Option<(u64, u64)>,
+    was_accessible: bool,
+    retry: bool,
+    metadata: Option<(u64, (u64, u64))>,
+) -> FollowAction {
+    let (new_len, new_inode) = match metadata {
+        None => return FollowAction::Gone,
+        Some(m) => m,
+    };
+
+    if retry && (!was_accessible || old_inode != Some(new_inode)) {
+        return FollowAction::Reappeared;
+    }
+
+    match new_len.cmp(&old_offset) {
+        Less => FollowAction::Truncated,
+        Equal => FollowAction::Unchanged,
+        Greater => FollowAction::Appended(new_len),
+    }
 }
 
 pub fn get_cli() -> MyResult<Cli> {
     Ok(Cli::parse())
 }
 
-fn count_lines_bytes(filename: &str) -> MyResult<(i64, i64)> {
+fn count_lines_bytes(filename: &str, delimiter: u8) -> MyResult<(i64, i64)> {
     let file = File::open(filename)?;
     let mut rdr = BufReader::new(file);
 
     let mut bytes: i64 = 0;
     let mut lines: i64 = 0;
-    let mut buf = String::new();
+    let mut buf = Vec::new();
     loop {
-        let line_bytes = rdr.read_line(&mut buf)? as i64;
+        let line_bytes = rdr.read_until(delimiter, &mut buf)? as i64;
         if line_bytes == 0 {
             break;
         }
 
         bytes += line_bytes;
         lines += 1;
+        buf.clear();
     }
 
     Ok((lines, bytes))
@@ -118,24 +186,93 @@ fn get_start_index(take_val: &TakeValue, total: i64) -> Option<u64> {
     }
 }
 
-fn print_lines(
+const REVERSE_READ_BLOCK_SIZE: u64 = 8 * 1024;
+
+fn find_reverse_line_start(file: &mut File, n: i64, delimiter: u8) -> MyResult<u64> {
+    let wanted = n.unsigned_abs();
+    let file_len = file.metadata()?.len();
+    let mut pos = file_len;
+    let mut found = 0u64;
+    let mut buf = vec![0u8; REVERSE_READ_BLOCK_SIZE as usize];
+
+    while pos > 0 {
+        let read_size = REVERSE_READ_BLOCK_SIZE.min(pos);
+        pos -= read_size;
+
+        file.seek(SeekFrom::Start(pos))?;
+        file.read_exact(&mut buf[..read_size as usize])?;
+
+        for idx in (0..read_size as usize).rev() {
+            if buf[idx] != delimiter {
+                continue;
+            }
+
+            let offset = pos + idx as u64;
+            if offset == file_len - 1 {
+                // Trailing delimiter: marks the end of output, not a boundary.
+                continue;
+            }
+
+            found += 1;
+            if found == wanted {
+                return Ok(offset + 1);
+            }
+        }
+    }
+
+    Ok(0)
+}
+
+fn stream_to_end(file: &mut File, start: u64) -> MyResult<()> {
+    file.seek(SeekFrom::Start(start))?;
+
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+    io::stdout().lock().write_all(&buf)?;
+
+    Ok(())
+}
+
+fn print_lines(filename: &str, num_lines: &TakeValue, delimiter: u8) -> MyResult<()> {
+    if let TakeNum(n) = num_lines {
+        if *n < 0 {
+            let mut file = File::open(filename)?;
+            let start = find_reverse_line_start(&mut file, *n, delimiter)?;
+            return stream_to_end(&mut file, start);
+        }
+    }
+
+    let (total_lines, _) = count_lines_bytes(filename, delimiter)?;
+    let file = File::open(filename)?;
+    print_lines_from_start(
+        BufReader::new(file),
+        io::stdout().lock(),
+        num_lines,
+        total_lines,
+        delimiter,
+    )
+}
+
+fn print_lines_from_start(
     mut file: impl BufRead,
+    mut out: impl Write,
     num_lines: &TakeValue,
     total_lines: i64,
+    delimiter: u8,
 ) -> MyResult<()>
 {
     if let Some(start) = get_start_index(num_lines, total_lines) {
         let mut line_count = 0;
-        let mut buf = String::new();
+        let mut buf = Vec::new();
 
         loop {
-            let bytes_read = file.read_line(&mut buf)?;
+            let bytes_read = file.read_until(delimiter, &mut buf)?;
             if bytes_read == 0 {
                 break;
             }
 
             if line_count >= start {
-                print!("{}", buf);
+                out.write_all(&buf)?;
             }
             line_count += 1;
             buf.clear();
@@ -147,6 +284,7 @@ fn print_lines(
 
 fn print_bytes<T>(
     mut file: T,
+    mut out: impl Write,
     num_bytes: &TakeValue,
     total_bytes: i64,
 ) -> MyResult<()>
@@ -159,19 +297,110 @@ fn print_bytes<T>(
 
             let mut buf = Vec::new();
             file.read_to_end(&mut buf)?;
-            print!("{}", String::from_utf8_lossy(&buf));
+
+            out.write_all(&buf)?;
         }
     }
 
     Ok(())
 }
 
+fn print_lines_stdin(
+    rdr: impl BufRead,
+    num_lines: &TakeValue,
+    delimiter: u8,
+    out: impl Write,
+) -> MyResult<()> {
+    if let TakeNum(n) = num_lines {
+        if *n < 0 {
+            return print_last_n_lines_stdin(rdr, out, n.unsigned_abs() as usize, delimiter);
+        }
+    }
+
+    // stdin has no total line count to give get_start_index up front, but
+    // none of the non-negative cases need one: whether a line clears the
+    // start index never depends on how many lines come after it, so a
+    // total larger than any real input is equivalent to the real count.
+    print_lines_from_start(rdr, out, num_lines, i64::MAX, delimiter)
+}
+
+fn print_last_n_lines_stdin(
+    mut rdr: impl BufRead,
+    mut out: impl Write,
+    capacity: usize,
+    delimiter: u8,
+) -> MyResult<()> {
+    let mut ring: VecDeque<Vec<u8>> = VecDeque::with_capacity(capacity);
+    let mut buf = Vec::new();
+    loop {
+        let bytes_read = rdr.read_until(delimiter, &mut buf)?;
+        if bytes_read == 0 {
+            break;
+        }
+
+        if capacity > 0 {
+            if ring.len() == capacity {
+                ring.pop_front();
+            }
+            ring.push_back(buf.clone());
+        }
+        buf.clear();
+    }
+
+    for line in &ring {
+        out.write_all(line)?;
+    }
+
+    Ok(())
+}
+
+fn print_bytes_stdin(mut rdr: impl Read, num_bytes: &TakeValue, out: impl Write) -> MyResult<()> {
+    let mut buf = Vec::new();
+    rdr.read_to_end(&mut buf)?;
+
+    let total_bytes = buf.len() as i64;
+    print_bytes(Cursor::new(buf), out, num_bytes, total_bytes)
+}
+
 pub fn run(cli: &Cli) -> MyResult<()> {
     let file_count = cli.files.len();
+    let follow = cli.follow || cli.retry;
+    let delimiter = if cli.zero_terminated { b'\0' } else { b'\n' };
+    let mut follow_states = Vec::new();
+    let mut last_header = None;
 
     for (i, filename) in cli.files.iter().enumerate() {
+        if filename == "-" {
+            if file_count > 1 && !cli.quiet {
+                println!(
+                    "{}==> standard input <==",
+                    if i > 0 { "\n" } else { "" },
+                );
+                last_header = Some(filename.clone());
+            }
+
+            let stdin = io::stdin();
+            if let Some(num_bytes) = cli.bytes.as_ref() {
+                print_bytes_stdin(stdin.lock(), num_bytes, io::stdout().lock())?;
+            } else {
+                print_lines_stdin(stdin.lock(), &cli.lines, delimiter, io::stdout().lock())?;
+            }
+
+            continue;
+        }
+
         match File::open(filename) {
-            Err(e) => eprintln!("{}: {}", filename, e),
+            Err(e) => {
+                eprintln!("{}: {}", filename, e);
+                if cli.retry {
+                    follow_states.push(FollowState {
+                        filename: filename.clone(),
+                        offset: 0,
+                        inode: None,
+                        accessible: false,
+                    });
+                }
+            }
             Ok(f) => {
                 if file_count > 1 && !cli.quiet {
                     println!(
@@ -179,25 +408,135 @@ pub fn run(cli: &Cli) -> MyResult<()> {
                         if i > 0 { "\n" } else { "" },
                         filename,
                     );
+                    last_header = Some(filename.clone());
                 }
 
-                let (total_lines, total_bytes) = count_lines_bytes(filename)?;
+                let total_bytes = f.metadata()?.len() as i64;
                 if cli.bytes.is_some() {
-                    print_bytes(f, &cli.bytes.as_ref().unwrap(), total_bytes)?;
-                    continue;
+                    print_bytes(f, io::stdout().lock(), &cli.bytes.as_ref().unwrap(), total_bytes)?;
+                } else {
+                    print_lines(filename, &cli.lines, delimiter)?;
                 }
 
-                print_lines(BufReader::new(f), &cli.lines, total_lines)?;
+                if follow {
+                    let inode = fs::metadata(filename)
+                        .ok()
+                        .map(|m| (m.dev(), m.ino()));
+                    follow_states.push(FollowState {
+                        filename: filename.clone(),
+                        offset: total_bytes as u64,
+                        inode,
+                        accessible: true,
+                    });
+                }
             }
         }
     }
 
+    if follow {
+        follow_files(
+            follow_states,
+            cli.quiet,
+            file_count > 1,
+            cli.sleep_interval,
+            cli.retry,
+            last_header,
+        )?;
+    }
+
     Ok(())
 }
 
+fn follow_files(
+    mut states: Vec<FollowState>,
+    quiet: bool,
+    multiple: bool,
+    sleep_interval: f64,
+    retry: bool,
+    mut last_header: Option<String>,
+) -> MyResult<()> {
+    let sleep_duration = Duration::from_secs_f64(sleep_interval);
+
+    loop {
+        for state in states.iter_mut() {
+            let metadata = fs::metadata(&state.filename)
+                .ok()
+                .map(|m| (m.len(), (m.dev(), m.ino())));
+
+            match classify(state.offset, state.inode, state.accessible, retry, metadata) {
+                FollowAction::Gone => {
+                    if state.accessible {
+                        eprintln!("{}: No such file or directory", state.filename);
+                    }
+                    state.accessible = false;
+                }
+                FollowAction::Reappeared => {
+                    eprintln!(
+                        "tailr: '{}': file has been replaced; following new file",
+                        state.filename
+                    );
+                    state.accessible = true;
+                    if retry {
+                        state.inode = metadata.map(|(_, inode)| inode);
+                    }
+                    state.offset = 0;
+                }
+                FollowAction::Truncated => {
+                    eprintln!("{}: file truncated", state.filename);
+                    state.accessible = true;
+                    if retry {
+                        state.inode = metadata.map(|(_, inode)| inode);
+                    }
+                    state.offset = 0;
+                }
+                FollowAction::Unchanged => {
+                    state.accessible = true;
+                    if retry {
+                        state.inode = metadata.map(|(_, inode)| inode);
+                    }
+                }
+                FollowAction::Appended(len) => {
+                    state.accessible = true;
+                    if retry {
+                        state.inode = metadata.map(|(_, inode)| inode);
+                    }
+
+                    let mut file = File::open(&state.filename)?;
+                    file.seek(SeekFrom::Start(state.offset))?;
+
+                    let mut buf = Vec::new();
+                    file.read_to_end(&mut buf)?;
+
+                    if multiple && !quiet
+                        && last_header.as_deref() != Some(state.filename.as_str())
+                    {
+                        println!(
+                            "{}==> {} <==",
+                            if last_header.is_some() { "\n" } else { "" },
+                            state.filename,
+                        );
+                    }
+                    io::stdout().lock().write_all(&buf)?;
+
+                    last_header = Some(state.filename.clone());
+                    state.offset = len;
+                }
+            }
+        }
+
+        thread::sleep(sleep_duration);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{count_lines_bytes, get_start_index, parse_take_value, PlusZero, TakeNum};
+    use std::io::Cursor;
+
+    use super::{
+        classify, count_lines_bytes, find_reverse_line_start, get_start_index, parse_take_value,
+        print_bytes_stdin, print_last_n_lines_stdin, print_lines_stdin, File, FollowAction,
+        PlusZero, TakeNum, REVERSE_READ_BLOCK_SIZE,
+    };
 
     #[test]
     fn test_parse_take_value() {
@@ -246,17 +585,78 @@ mod tests {
         assert_eq!(res.unwrap_err(), "illegal lines count -- foo");
     }
 
+    #[test]
+    fn test_classify_retry_rotation() {
+        // File gets replaced under the same name (e.g. log rotation): the
+        // device/inode pair changes even though the path is unchanged.
+        let old_inode = (1, 100);
+        let new_inode = (1, 101);
+        assert_eq!(
+            classify(4, Some(old_inode), true, true, Some((4, new_inode))),
+            FollowAction::Reappeared
+        );
+    }
+
+    #[test]
+    fn test_classify_retry_disappear_then_reappear() {
+        // A path that previously failed to open (never accessible) later
+        // exists: it should be treated as a fresh file, not resumed.
+        assert_eq!(
+            classify(0, None, false, true, Some((3, (1, 1)))),
+            FollowAction::Reappeared
+        );
+
+        // A path that goes missing between polls reports Gone until it
+        // comes back, at which point it's picked up from the start.
+        assert_eq!(classify(10, Some((1, 1)), true, true, None), FollowAction::Gone);
+        assert_eq!(
+            classify(10, Some((1, 1)), false, true, Some((2, (1, 1)))),
+            FollowAction::Reappeared
+        );
+    }
+
+    #[test]
+    fn test_classify_without_retry_ignores_inode_changes() {
+        // Without --retry, an inode change is invisible: the file is only
+        // ever treated as truncated, unchanged, or appended by length.
+        assert_eq!(
+            classify(10, Some((1, 1)), true, false, Some((20, (1, 2)))),
+            FollowAction::Appended(20)
+        );
+    }
+
     #[test]
     fn test_count_lines_bytes() {
-        let res = count_lines_bytes("tests/inputs/one.txt");
+        let res = count_lines_bytes("tests/inputs/one.txt", b'\n');
         assert!(res.is_ok());
         assert_eq!(res.unwrap(), (1, 24));
 
-        let res = count_lines_bytes("tests/inputs/ten.txt");
+        let res = count_lines_bytes("tests/inputs/ten.txt", b'\n');
         assert!(res.is_ok());
         assert_eq!(res.unwrap(), (10, 49));
     }
 
+    #[test]
+    fn test_count_lines_bytes_zero_terminated() {
+        let path = write_temp_file("zero-terminated.bin", b"one\0two\0three\0");
+        let res = count_lines_bytes(path.to_str().unwrap(), b'\0');
+        assert_eq!(res.unwrap(), (3, 14));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_count_lines_bytes_binary_safe() {
+        // Non-UTF-8 bytes (the byte 0xFF never occurs in valid UTF-8)
+        // shouldn't trip up counting, since it works on raw bytes rather
+        // than requiring a valid `String`.
+        let path = write_temp_file("binary.bin", b"\xff\xfe\n\x00\xff\n");
+        let res = count_lines_bytes(path.to_str().unwrap(), b'\n');
+        assert_eq!(res.unwrap(), (2, 6));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
     #[test]
     fn test_get_start_index() {
         assert_eq!(get_start_index(&PlusZero, 0), None);
@@ -277,5 +677,174 @@ mod tests {
 
         assert_eq!(get_start_index(&TakeNum(-20), 10), Some(0));
     }
+
+    #[test]
+    fn test_classify() {
+        let inode_a = (1, 1);
+        let inode_b = (1, 2);
+
+        // File has disappeared.
+        assert_eq!(
+            classify(10, Some(inode_a), true, false, None),
+            FollowAction::Gone
+        );
+
+        // No new bytes.
+        assert_eq!(
+            classify(10, Some(inode_a), true, false, Some((10, inode_a))),
+            FollowAction::Unchanged
+        );
+
+        // New bytes appended.
+        assert_eq!(
+            classify(10, Some(inode_a), true, false, Some((20, inode_a))),
+            FollowAction::Appended(20)
+        );
+
+        // Shrunk in place (not --retry): treated as a truncation.
+        assert_eq!(
+            classify(10, Some(inode_a), true, false, Some((5, inode_a))),
+            FollowAction::Truncated
+        );
+
+        // --retry: same inode, just grew.
+        assert_eq!(
+            classify(10, Some(inode_a), true, true, Some((20, inode_a))),
+            FollowAction::Appended(20)
+        );
+
+        // --retry: inode changed underneath the same name (rotation).
+        assert_eq!(
+            classify(10, Some(inode_a), true, true, Some((3, inode_b))),
+            FollowAction::Reappeared
+        );
+
+        // --retry: a previously inaccessible path now exists.
+        assert_eq!(
+            classify(0, None, false, true, Some((3, inode_a))),
+            FollowAction::Reappeared
+        );
+    }
+
+    fn write_temp_file(name: &str, contents: &[u8]) -> std::path::PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!("tailr-test-{}-{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_find_reverse_line_start_short_file() {
+        let path = write_temp_file("short.txt", b"a\nb\nc\nd\n");
+        let mut file = File::open(&path).unwrap();
+
+        assert_eq!(find_reverse_line_start(&mut file, -1, b'\n').unwrap(), 6);
+        assert_eq!(find_reverse_line_start(&mut file, -2, b'\n').unwrap(), 4);
+        assert_eq!(find_reverse_line_start(&mut file, -10, b'\n').unwrap(), 0);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_find_reverse_line_start_no_trailing_delimiter() {
+        let path = write_temp_file("no-trailing.txt", b"a\nb\nc");
+        let mut file = File::open(&path).unwrap();
+
+        assert_eq!(find_reverse_line_start(&mut file, -1, b'\n').unwrap(), 4);
+        assert_eq!(find_reverse_line_start(&mut file, -2, b'\n').unwrap(), 2);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_find_reverse_line_start_spans_multiple_blocks() {
+        // Each line is 10 bytes, so this comfortably spans several
+        // `REVERSE_READ_BLOCK_SIZE` blocks and exercises the block-boundary
+        // bookkeeping, not just a single read. Asking for two blocks' worth
+        // of lines puts the start position before the last block.
+        let line_count = (REVERSE_READ_BLOCK_SIZE as usize / 10) * 3;
+        let contents: Vec<u8> = (0..line_count)
+            .map(|i| format!("{:09}\n", i))
+            .collect::<String>()
+            .into_bytes();
+        let path = write_temp_file("multi-block.txt", &contents);
+        let mut file = File::open(&path).unwrap();
+
+        let wanted = (REVERSE_READ_BLOCK_SIZE as usize / 10) * 2;
+        let start = find_reverse_line_start(&mut file, -(wanted as i64), b'\n').unwrap();
+        let expected = contents.len() - wanted * 10;
+        assert_eq!(start, expected as u64);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_find_reverse_line_start_zero_terminated() {
+        let path = write_temp_file("zero-terminated.bin", b"one\0two\0three\0");
+        let mut file = File::open(&path).unwrap();
+
+        assert_eq!(find_reverse_line_start(&mut file, -1, b'\0').unwrap(), 8);
+        assert_eq!(find_reverse_line_start(&mut file, -2, b'\0').unwrap(), 4);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_find_reverse_line_start_binary_safe() {
+        let path = write_temp_file("binary.bin", b"\xff\xfe\xfd\n\x00\x01\xff\n");
+        let mut file = File::open(&path).unwrap();
+
+        assert_eq!(find_reverse_line_start(&mut file, -1, b'\n').unwrap(), 4);
+        assert_eq!(find_reverse_line_start(&mut file, -2, b'\n').unwrap(), 0);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_print_lines_stdin_positive_n() {
+        // TakeNum(2) here means "starting from line 2" (what GNU tail's
+        // `-n +2` produces), not "the first 2 lines".
+        let mut out = Vec::new();
+        print_lines_stdin(Cursor::new(b"a\nb\nc\nd\n".as_slice()), &TakeNum(2), b'\n', &mut out)
+            .unwrap();
+        assert_eq!(out, b"b\nc\nd\n");
+    }
+
+    #[test]
+    fn test_print_lines_stdin_plus_zero() {
+        let mut out = Vec::new();
+        print_lines_stdin(Cursor::new(b"a\nb\n".as_slice()), &PlusZero, b'\n', &mut out).unwrap();
+        assert_eq!(out, b"a\nb\n");
+    }
+
+    #[test]
+    fn test_print_lines_stdin_negative_n_delegates_to_ring_buffer() {
+        let mut out = Vec::new();
+        print_lines_stdin(Cursor::new(b"a\nb\nc\nd\n".as_slice()), &TakeNum(-2), b'\n', &mut out)
+            .unwrap();
+        assert_eq!(out, b"c\nd\n");
+    }
+
+    #[test]
+    fn test_print_last_n_lines_stdin() {
+        let mut out = Vec::new();
+        print_last_n_lines_stdin(Cursor::new(b"a\nb\nc\n".as_slice()), &mut out, 2, b'\n').unwrap();
+        assert_eq!(out, b"b\nc\n");
+
+        let mut out = Vec::new();
+        print_last_n_lines_stdin(Cursor::new(b"a\nb\n".as_slice()), &mut out, 0, b'\n').unwrap();
+        assert_eq!(out, b"");
+
+        let mut out = Vec::new();
+        print_last_n_lines_stdin(Cursor::new(b"a\n".as_slice()), &mut out, 5, b'\n').unwrap();
+        assert_eq!(out, b"a\n");
+    }
+
+    #[test]
+    fn test_print_bytes_stdin() {
+        let mut out = Vec::new();
+        print_bytes_stdin(Cursor::new(b"hello world".as_slice()), &TakeNum(-5), &mut out).unwrap();
+        assert_eq!(out, b"world");
+    }
 }
 